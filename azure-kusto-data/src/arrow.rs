@@ -1,18 +1,23 @@
 use std::convert::TryInto;
 
+use std::io::Write;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use arrow::array::TimestampNanosecondArray;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, DurationNanosecondArray, Float64Array, Int32Array, Int64Array,
-        StringArray,
+        ArrayRef, BooleanArray, Decimal128Array, DurationNanosecondArray, Float64Array, Int32Array,
+        Int64Array, ListArray, StringArray, StructArray,
     },
-    datatypes::{DataType, Field, Schema, TimeUnit},
+    buffer::{NullBuffer, OffsetBuffer},
+    datatypes::{DataType, Field, Fields, Schema, SchemaRef, TimeUnit},
+    ipc::writer::{FileWriter, StreamWriter},
     record_batch::RecordBatch,
 };
-use azure_core::error::{ErrorKind, ResultExt};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
 use serde_json::Value;
 
 use crate::error::Result;
@@ -86,7 +91,369 @@ fn convert_array_i64(values: Vec<Value>) -> Result<ArrayRef> {
     Ok(Arc::new(Int64Array::from(ints)))
 }
 
+/// Splits a `[-+]?digits[.digits]?` decimal string into sign/integer/fraction.
+fn decimal_parts(value: &str) -> (bool, &str, &str) {
+    let (negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    match rest.split_once('.') {
+        Some((int_part, frac_part)) => (negative, int_part, frac_part),
+        None => (negative, rest, ""),
+    }
+}
+
+fn convert_array_decimal(values: Vec<Value>) -> Result<(ArrayRef, u8, i8)> {
+    let raw: Vec<Option<String>> = values
+        .into_iter()
+        .map(|value| match value {
+            Value::Null => Ok(None),
+            Value::String(s) if s.is_empty() => Ok(None),
+            Value::String(s) => Ok(Some(s)),
+            Value::Number(n) => Ok(Some(n.to_string())),
+            other => Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Unsupported decimal value: {other}"),
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let max_frac_len = raw
+        .iter()
+        .flatten()
+        .map(|s| decimal_parts(s).2.len())
+        .max()
+        .unwrap_or(0);
+    let scale = max_frac_len.min(38) as i8;
+
+    // `precision` must be at least `scale` for `with_precision_and_scale` to
+    // accept it (a column of only small-magnitude values like "0.05" has few
+    // significant digits but still needs a precision covering its scale).
+    let mut precision: u8 = (scale as u8).max(1);
+    let mut digits: Vec<Option<i128>> = Vec::with_capacity(raw.len());
+    for value in raw {
+        let Some(value) = value else {
+            digits.push(None);
+            continue;
+        };
+        let (negative, int_part, frac_part) = decimal_parts(&value);
+
+        if frac_part.len() > scale as usize {
+            // `scale` was capped at 38 below the digits this value actually
+            // carries; padding/truncating it would silently drop precision.
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Decimal value '{value}' exceeds the maximum precision of 38"),
+            ));
+        }
+
+        let mut combined = String::with_capacity(int_part.len() + scale as usize);
+        combined.push_str(int_part);
+        combined.push_str(frac_part);
+        combined.extend(std::iter::repeat('0').take(scale as usize - frac_part.len()));
+
+        let digit_count = combined.trim_start_matches('0').len().max(1) as u8;
+        if digit_count > 38 {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Decimal value '{value}' exceeds the maximum precision of 38"),
+            ));
+        }
+        precision = precision.max(digit_count);
+
+        let mut parsed: i128 = combined.parse().map_err(|_| {
+            Error::message(
+                ErrorKind::DataConversion,
+                format!("Failed to parse decimal value '{value}'"),
+            )
+        })?;
+        if negative {
+            parsed = -parsed;
+        }
+        digits.push(Some(parsed));
+    }
+
+    let array = Decimal128Array::from(digits)
+        .with_precision_and_scale(precision, scale)
+        .context(ErrorKind::DataConversion, "Invalid decimal precision/scale")?;
+    Ok((Arc::new(array), precision, scale))
+}
+
+/// Controls how individual Kusto column types are converted to Arrow.
+#[derive(Debug, Clone, Copy)]
+pub struct ConvertOptions {
+    /// Expand `dynamic` columns into nested Struct/List arrays instead of a raw JSON string column.
+    pub expand_dynamic: bool,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            expand_dynamic: true,
+        }
+    }
+}
+
+/// A merged JSON shape for a `dynamic` column, used to pick one Arrow type every value can coerce into.
+#[derive(Debug, Clone, PartialEq)]
+enum JsonType {
+    Null,
+    Bool,
+    Int,
+    Long,
+    Real,
+    Utf8,
+    List(Box<JsonType>),
+    Struct(Vec<(String, JsonType)>),
+}
+
+fn infer_json_type(value: &Value) -> JsonType {
+    match value {
+        Value::Null => JsonType::Null,
+        Value::Bool(_) => JsonType::Bool,
+        Value::Number(n) => match n.as_i64() {
+            Some(i) if i32::try_from(i).is_ok() => JsonType::Int,
+            Some(_) => JsonType::Long,
+            None => JsonType::Real,
+        },
+        Value::String(_) => JsonType::Utf8,
+        Value::Array(items) => JsonType::List(Box::new(
+            items
+                .iter()
+                .map(infer_json_type)
+                .fold(JsonType::Null, merge_json_type),
+        )),
+        Value::Object(map) => JsonType::Struct(
+            map.iter()
+                .map(|(key, value)| (key.clone(), infer_json_type(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Widens `Int` -> `Long` -> `Real`, falling back to `Utf8` on any other conflict.
+fn merge_json_type(a: JsonType, b: JsonType) -> JsonType {
+    use JsonType::*;
+    match (a, b) {
+        (Null, other) | (other, Null) => other,
+        (Bool, Bool) => Bool,
+        (Int, Int) => Int,
+        (Int, Long) | (Long, Int) | (Long, Long) => Long,
+        (Real, Real) | (Int, Real) | (Real, Int) | (Long, Real) | (Real, Long) => Real,
+        (Utf8, Utf8) => Utf8,
+        (List(a), List(b)) => List(Box::new(merge_json_type(*a, *b))),
+        (Struct(a), Struct(b)) => Struct(merge_struct_fields(a, b)),
+        _ => Utf8,
+    }
+}
+
+fn merge_struct_fields(
+    mut merged: Vec<(String, JsonType)>,
+    additional: Vec<(String, JsonType)>,
+) -> Vec<(String, JsonType)> {
+    for (key, ty) in additional {
+        match merged.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = merge_json_type(existing.clone(), ty),
+            None => merged.push((key, ty)),
+        }
+    }
+    merged
+}
+
+fn json_type_to_arrow(ty: &JsonType) -> DataType {
+    match ty {
+        JsonType::Null | JsonType::Utf8 => DataType::Utf8,
+        JsonType::Bool => DataType::Boolean,
+        JsonType::Int => DataType::Int32,
+        JsonType::Long => DataType::Int64,
+        JsonType::Real => DataType::Float64,
+        JsonType::List(item) => {
+            DataType::List(Arc::new(Field::new("item", json_type_to_arrow(item), true)))
+        }
+        JsonType::Struct(fields) => DataType::Struct(Fields::from(
+            fields
+                .iter()
+                .map(|(name, ty)| Field::new(name, json_type_to_arrow(ty), true))
+                .collect::<Vec<_>>(),
+        )),
+    }
+}
+
+fn value_to_utf8(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn build_json_array(values: &[Value], ty: &JsonType) -> Result<ArrayRef> {
+    match ty {
+        JsonType::Null | JsonType::Utf8 => {
+            let strings: Vec<Option<String>> = values.iter().map(value_to_utf8).collect();
+            let strings: Vec<Option<&str>> = strings.iter().map(Option::as_deref).collect();
+            Ok(Arc::new(StringArray::from(strings)))
+        }
+        JsonType::Bool => Ok(Arc::new(BooleanArray::from(
+            values.iter().map(Value::as_bool).collect::<Vec<_>>(),
+        ))),
+        JsonType::Int => Ok(Arc::new(Int32Array::from(
+            values
+                .iter()
+                .map(|v| v.as_i64().and_then(|i| i32::try_from(i).ok()))
+                .collect::<Vec<_>>(),
+        ))),
+        JsonType::Long => Ok(Arc::new(Int64Array::from(
+            values.iter().map(Value::as_i64).collect::<Vec<_>>(),
+        ))),
+        JsonType::Real => Ok(Arc::new(Float64Array::from(
+            values.iter().map(Value::as_f64).collect::<Vec<_>>(),
+        ))),
+        JsonType::List(item) => build_list_array(values, item),
+        JsonType::Struct(fields) => build_struct_array(values, fields),
+    }
+}
+
+fn build_list_array(values: &[Value], item_ty: &JsonType) -> Result<ArrayRef> {
+    let mut offsets: Vec<i32> = Vec::with_capacity(values.len() + 1);
+    let mut validity: Vec<bool> = Vec::with_capacity(values.len());
+    let mut child_values: Vec<Value> = Vec::new();
+    let mut offset = 0i32;
+    offsets.push(offset);
+
+    for value in values {
+        match value {
+            Value::Null => validity.push(false),
+            Value::Array(items) => {
+                validity.push(true);
+                offset += items.len() as i32;
+                child_values.extend(items.iter().cloned());
+            }
+            // A scalar where a list is expected. `merge_json_type` widens a list
+            // mixed with scalars to `Utf8`, so this arm is only a fallback: it
+            // treats the scalar as a single-element list rather than erroring.
+            other => {
+                validity.push(true);
+                offset += 1;
+                child_values.push(other.clone());
+            }
+        }
+        offsets.push(offset);
+    }
+
+    let child = build_json_array(&child_values, item_ty)?;
+    let field = Arc::new(Field::new("item", json_type_to_arrow(item_ty), true));
+    let array = ListArray::try_new(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        child,
+        Some(NullBuffer::from(validity)),
+    )
+    .context(
+        ErrorKind::DataConversion,
+        "Failed to build dynamic list array",
+    )?;
+    Ok(Arc::new(array))
+}
+
+fn build_struct_array(values: &[Value], fields: &[(String, JsonType)]) -> Result<ArrayRef> {
+    let validity: Vec<bool> = values.iter().map(|v| !matches!(v, Value::Null)).collect();
+
+    if fields.is_empty() {
+        // An empty/deeply-nested `{}` has no keys to infer a type from; it's
+        // still a valid (if field-less) struct rather than an error.
+        let array = StructArray::try_new_with_length(
+            Fields::empty(),
+            vec![],
+            Some(NullBuffer::from(validity)),
+            values.len(),
+        )
+        .context(
+            ErrorKind::DataConversion,
+            "Failed to build empty dynamic struct array",
+        )?;
+        return Ok(Arc::new(array));
+    }
+
+    let arrow_fields: Vec<Field> = fields
+        .iter()
+        .map(|(name, ty)| Field::new(name, json_type_to_arrow(ty), true))
+        .collect();
+
+    let mut child_values: Vec<Vec<Value>> = fields
+        .iter()
+        .map(|_| Vec::with_capacity(values.len()))
+        .collect();
+    for value in values {
+        let object = match value {
+            Value::Object(map) => Some(map),
+            _ => None,
+        };
+        for (i, (name, _)) in fields.iter().enumerate() {
+            let field_value = object
+                .and_then(|map| map.get(name))
+                .cloned()
+                .unwrap_or(Value::Null);
+            child_values[i].push(field_value);
+        }
+    }
+
+    let child_arrays = fields
+        .iter()
+        .zip(child_values)
+        .map(|((_, ty), values)| build_json_array(&values, ty))
+        .collect::<Result<Vec<_>>>()?;
+
+    let array = StructArray::try_new(
+        Fields::from(arrow_fields),
+        child_arrays,
+        Some(NullBuffer::from(validity)),
+    )
+    .context(
+        ErrorKind::DataConversion,
+        "Failed to build dynamic struct array",
+    )?;
+    Ok(Arc::new(array))
+}
+
+fn convert_array_dynamic_raw(values: Vec<Value>) -> Result<ArrayRef> {
+    let strings: Vec<Option<String>> = values
+        .into_iter()
+        .map(|value| match value {
+            Value::Null => Ok(None),
+            other => Ok(Some(serde_json::to_string(&other)?)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let strings: Vec<Option<&str>> = strings.iter().map(Option::as_deref).collect();
+    Ok(Arc::new(StringArray::from(strings)))
+}
+
+fn convert_array_dynamic(
+    values: Vec<Value>,
+    options: &ConvertOptions,
+) -> Result<(DataType, ArrayRef)> {
+    if !options.expand_dynamic {
+        return Ok((DataType::Utf8, convert_array_dynamic_raw(values)?));
+    }
+
+    let merged_type = values
+        .iter()
+        .map(infer_json_type)
+        .fold(JsonType::Null, merge_json_type);
+    let data_type = json_type_to_arrow(&merged_type);
+    let array = build_json_array(&values, &merged_type)?;
+    Ok((data_type, array))
+}
+
 pub fn convert_column(data: Vec<Value>, column: &Column) -> Result<(Field, ArrayRef)> {
+    convert_column_with_options(data, column, &ConvertOptions::default())
+}
+
+pub fn convert_column_with_options(
+    data: Vec<Value>,
+    column: &Column,
+    options: &ConvertOptions,
+) -> Result<(Field, ArrayRef)> {
     let column_name = &column.column_name;
     match column.column_type {
         ColumnType::String => convert_array_string(data)
@@ -115,19 +482,74 @@ pub fn convert_column(data: Vec<Value>, column: &Column) -> Result<(Field, Array
                 data,
             )
         }),
+        ColumnType::Decimal => {
+            let (data, precision, scale) = convert_array_decimal(data)?;
+            Ok((
+                Field::new(column_name, DataType::Decimal128(precision, scale), true),
+                data,
+            ))
+        }
+        ColumnType::Dynamic => {
+            let (data_type, data) = convert_array_dynamic(data, options)?;
+            Ok((Field::new(column_name, data_type, true), data))
+        }
         _ => todo!(),
     }
 }
 
+/// A reasonable `batch_size` to pass to [`convert_table_streaming`].
+pub const DEFAULT_BATCH_SIZE: usize = 8192;
+
 pub fn convert_table(table: DataTable) -> Result<RecordBatch> {
-    let mut buffer: Vec<Vec<Value>> = Vec::with_capacity(table.columns.len());
-    let mut fields: Vec<Field> = Vec::with_capacity(table.columns.len());
-    let mut columns: Vec<ArrayRef> = Vec::with_capacity(table.columns.len());
+    convert_table_with_options(table, &ConvertOptions::default())
+}
+
+pub fn convert_table_with_options(
+    table: DataTable,
+    options: &ConvertOptions,
+) -> Result<RecordBatch> {
+    rows_to_batch(&table.columns, table.rows, options)
+}
 
-    for _ in 0..table.columns.len() {
-        buffer.push(Vec::with_capacity(table.rows.len()));
+/// Converts `table` into an iterator of [`RecordBatch`]es of at most `batch_size` rows each.
+pub fn convert_table_streaming(
+    table: DataTable,
+    batch_size: usize,
+    options: ConvertOptions,
+) -> Result<impl Iterator<Item = Result<RecordBatch>>> {
+    if batch_size == 0 {
+        return Err(Error::message(
+            ErrorKind::DataConversion,
+            "batch_size must be greater than zero",
+        ));
     }
-    table.rows.into_iter().for_each(|row| match row {
+
+    let columns = table.columns;
+    let mut rows = table.rows.into_iter();
+    Ok(std::iter::from_fn(move || {
+        let chunk: Vec<Value> = (&mut rows).take(batch_size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(rows_to_batch(&columns, chunk, &options))
+        }
+    }))
+}
+
+/// Shared by [`convert_table`] and [`convert_table_streaming`].
+fn rows_to_batch(
+    table_columns: &[Column],
+    rows: Vec<Value>,
+    options: &ConvertOptions,
+) -> Result<RecordBatch> {
+    let mut buffer: Vec<Vec<Value>> = Vec::with_capacity(table_columns.len());
+    let mut fields: Vec<Field> = Vec::with_capacity(table_columns.len());
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(table_columns.len());
+
+    for _ in 0..table_columns.len() {
+        buffer.push(Vec::with_capacity(rows.len()));
+    }
+    rows.into_iter().for_each(|row| match row {
         Value::Array(v) => {
             v.into_iter().enumerate().for_each(|(i, v)| {
                 buffer[i].push(v);
@@ -138,8 +560,8 @@ pub fn convert_table(table: DataTable) -> Result<RecordBatch> {
 
     buffer
         .into_iter()
-        .zip(table.columns.into_iter())
-        .map(|(data, column)| convert_column(data, &column))
+        .zip(table_columns.iter())
+        .map(|(data, column)| convert_column_with_options(data, column, options))
         .try_for_each::<_, Result<()>>(|result| {
             let (field, data) = result?;
             fields.push(field);
@@ -151,6 +573,139 @@ pub fn convert_table(table: DataTable) -> Result<RecordBatch> {
         .context(ErrorKind::DataConversion, "Failed to create record batch")?)
 }
 
+/// Writes `batches` (which must all share `schema`) as an Arrow IPC file (with footer).
+pub fn write_ipc_file<W: Write>(
+    schema: &Schema,
+    batches: impl Iterator<Item = Result<RecordBatch>>,
+    writer: W,
+) -> Result<()> {
+    let mut ipc_writer = FileWriter::try_new(writer, schema)
+        .context(ErrorKind::Io, "Failed to create Arrow IPC file writer")?;
+    for batch in batches {
+        ipc_writer
+            .write(&batch?)
+            .context(ErrorKind::Io, "Failed to write record batch to IPC file")?;
+    }
+    ipc_writer
+        .finish()
+        .context(ErrorKind::Io, "Failed to finish Arrow IPC file")?;
+    Ok(())
+}
+
+/// Writes `batches` (which must all share `schema`) as an Arrow IPC stream (no footer).
+pub fn write_ipc_stream<W: Write>(
+    schema: &Schema,
+    batches: impl Iterator<Item = Result<RecordBatch>>,
+    writer: W,
+) -> Result<()> {
+    let mut ipc_writer = StreamWriter::try_new(writer, schema)
+        .context(ErrorKind::Io, "Failed to create Arrow IPC stream writer")?;
+    for batch in batches {
+        ipc_writer
+            .write(&batch?)
+            .context(ErrorKind::Io, "Failed to write record batch to IPC stream")?;
+    }
+    ipc_writer
+        .finish()
+        .context(ErrorKind::Io, "Failed to finish Arrow IPC stream")?;
+    Ok(())
+}
+
+/// Convenience wrapper over [`write_ipc_file`] that returns the bytes directly.
+pub fn to_ipc_bytes(
+    schema: &Schema,
+    batches: impl Iterator<Item = Result<RecordBatch>>,
+) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_ipc_file(schema, batches, &mut buffer)?;
+    Ok(buffer)
+}
+
+// Kept here rather than in operations/query.rs, next to the rest of the Arrow export logic.
+impl crate::operations::query::KustoResponseDataSetV2 {
+    /// Serializes every record batch in this response to the Arrow IPC file format.
+    pub fn write_ipc<W: Write>(&self, w: W) -> Result<()> {
+        let mut batches = self.record_batches().peekable();
+        let schema = match batches.peek() {
+            Some(Ok(batch)) => batch.schema(),
+            Some(Err(_)) => return Err(batches.next().expect("just peeked").unwrap_err()),
+            None => Arc::new(Schema::empty()),
+        };
+        write_ipc_file(&schema, batches, w)
+    }
+
+    /// Convenience wrapper over [`Self::write_ipc`] that returns the bytes directly.
+    pub fn to_ipc_bytes(&self) -> Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.write_ipc(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Like [`Self::record_batches`], but splits any batch bigger than
+    /// `batch_size` rows into smaller ones (see [`DEFAULT_BATCH_SIZE`] for a
+    /// reasonable default). Each table is still converted into one full batch
+    /// first and the chunks are zero-copy slices of it, so this does not lower
+    /// peak memory; [`convert_table_streaming`] converts a table chunk by chunk.
+    pub fn record_batches_streaming(
+        &self,
+        batch_size: usize,
+    ) -> Result<impl Iterator<Item = Result<RecordBatch>> + '_> {
+        if batch_size == 0 {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                "batch_size must be greater than zero",
+            ));
+        }
+
+        Ok(self.record_batches().flat_map(move |batch| match batch {
+            Ok(batch) => (0..batch.num_rows().max(1))
+                .step_by(batch_size)
+                .map(|offset| Ok(batch.slice(offset, batch_size.min(batch.num_rows() - offset))))
+                .collect::<Vec<_>>(),
+            Err(err) => vec![Err(err)],
+        }))
+    }
+}
+
+/// Streams `batches` (which must all share `schema`) into a single Parquet file/buffer via [`ArrowWriter`].
+pub fn write_parquet<W: Write + Send>(
+    schema: SchemaRef,
+    batches: impl Iterator<Item = Result<RecordBatch>>,
+    writer: W,
+    props: Option<WriterProperties>,
+) -> Result<()> {
+    let mut parquet_writer = ArrowWriter::try_new(writer, schema, props)
+        .context(ErrorKind::Io, "Failed to create Parquet writer")?;
+    for batch in batches {
+        parquet_writer
+            .write(&batch?)
+            .context(ErrorKind::Io, "Failed to write record batch to Parquet")?;
+    }
+    parquet_writer
+        .close()
+        .context(ErrorKind::Io, "Failed to finish Parquet file")?;
+    Ok(())
+}
+
+impl crate::operations::query::KustoResponseDataSetV2 {
+    /// Serializes every record batch in this response into a single Parquet file/buffer.
+    pub fn write_parquet<W: Write + Send>(
+        &self,
+        w: W,
+        props: Option<WriterProperties>,
+    ) -> Result<()> {
+        let mut batches = self.record_batches().peekable();
+        let schema = match batches.peek() {
+            Some(Ok(batch)) => batch.schema(),
+            Some(Err(_)) => return Err(batches.next().expect("just peeked").unwrap_err()),
+            None => Arc::new(Schema::empty()),
+        };
+        write_parquet(schema, batches, w, props)
+    }
+}
+
+pub mod json_integration;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +713,23 @@ mod tests {
     use crate::operations::query::KustoResponseDataSetV2;
     use std::path::PathBuf;
 
+    /// A single `int_col` table with one row per value in `rows`.
+    fn int_column_table(rows: impl IntoIterator<Item = i64>) -> DataTable {
+        DataTable {
+            table_id: 1,
+            table_name: "Deft".to_string(),
+            table_kind: TableKind::PrimaryResult,
+            columns: vec![Column {
+                column_name: "int_col".to_string(),
+                column_type: ColumnType::Int,
+            }],
+            rows: rows
+                .into_iter()
+                .map(|i| Value::Array(vec![Value::from(i)]))
+                .collect(),
+        }
+    }
+
     #[test]
     fn deserialize_column() {
         let data = r#" {
@@ -203,6 +775,426 @@ mod tests {
         assert_eq!(t, ref_tbl);
     }
 
+    #[test]
+    fn convert_decimal_column() {
+        let column = Column {
+            column_name: "decimal_col".to_string(),
+            column_type: ColumnType::Decimal,
+        };
+        let values = vec![
+            Value::String("1.50".to_string()),
+            Value::String("-2.125".to_string()),
+            Value::Null,
+        ];
+
+        let (field, array) = convert_column(values, &column).expect("conversion error");
+        assert_eq!(field.data_type(), &DataType::Decimal128(4, 3));
+
+        let array = array
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .expect("expected Decimal128Array");
+        assert_eq!(array.value(0), 1500);
+        assert_eq!(array.value(1), -2125);
+        assert!(array.is_null(2));
+    }
+
+    #[test]
+    fn convert_decimal_column_small_magnitude() {
+        let column = Column {
+            column_name: "decimal_col".to_string(),
+            column_type: ColumnType::Decimal,
+        };
+        let values = vec![Value::String("0.05".to_string())];
+
+        let (field, array) = convert_column(values, &column).expect("conversion error");
+        // precision must be >= scale even though "0.05" has only one
+        // significant digit.
+        assert_eq!(field.data_type(), &DataType::Decimal128(2, 2));
+
+        let array = array
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .expect("expected Decimal128Array");
+        assert_eq!(array.value(0), 5);
+    }
+
+    #[test]
+    fn convert_decimal_column_rejects_excess_fraction_digits() {
+        let column = Column {
+            column_name: "decimal_col".to_string(),
+            column_type: ColumnType::Decimal,
+        };
+        let values = vec![Value::String(format!("1.{}", "0".repeat(39)))];
+
+        let err = convert_column(values, &column).expect_err("expected precision error");
+        assert!(err
+            .to_string()
+            .contains("exceeds the maximum precision of 38"));
+    }
+
+    #[test]
+    fn convert_decimal_column_rejects_excess_total_digits() {
+        let column = Column {
+            column_name: "decimal_col".to_string(),
+            column_type: ColumnType::Decimal,
+        };
+        let values = vec![Value::String("9".repeat(39))];
+
+        let err = convert_column(values, &column).expect_err("expected precision error");
+        assert!(err
+            .to_string()
+            .contains("exceeds the maximum precision of 38"));
+    }
+
+    #[test]
+    fn convert_decimal_column_rejects_non_numeric_value() {
+        let column = Column {
+            column_name: "decimal_col".to_string(),
+            column_type: ColumnType::Decimal,
+        };
+        let values = vec![Value::Bool(true)];
+
+        let err = convert_column(values, &column).expect_err("expected unsupported value error");
+        assert!(err.to_string().contains("Unsupported decimal value"));
+    }
+
+    #[test]
+    fn convert_dynamic_column_expanded() {
+        let column = Column {
+            column_name: "dynamic_col".to_string(),
+            column_type: ColumnType::Dynamic,
+        };
+        let values = vec![
+            serde_json::json!({"a": 1, "b": "x"}),
+            serde_json::json!({"a": 2}),
+            Value::Null,
+        ];
+
+        let (field, data) = convert_column(values, &column).expect("conversion error");
+        assert_eq!(
+            field.data_type(),
+            &DataType::Struct(Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ]))
+        );
+
+        let array = data
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("expected StructArray");
+        assert!(array.is_valid(0));
+        assert!(array.is_valid(1));
+        assert!(array.is_null(2));
+    }
+
+    #[test]
+    fn convert_dynamic_column_list() {
+        let column = Column {
+            column_name: "dynamic_col".to_string(),
+            column_type: ColumnType::Dynamic,
+        };
+        let values = vec![serde_json::json!([1, 2, 3]), Value::Null];
+
+        let (field, data) = convert_column(values, &column).expect("conversion error");
+        assert_eq!(
+            field.data_type(),
+            &DataType::List(Arc::new(Field::new("item", DataType::Int32, true)))
+        );
+
+        let array = data
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .expect("expected ListArray");
+        assert!(array.is_valid(0));
+        assert!(array.is_null(1));
+        let first = array.value(0);
+        let first = first.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(first.values(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn convert_dynamic_column_widens_int_long_real() {
+        let column = Column {
+            column_name: "dynamic_col".to_string(),
+            column_type: ColumnType::Dynamic,
+        };
+        // A column mixing a 32-bit int, a value too large for i32, and a
+        // float must widen to the type every value can be coerced into.
+        let values = vec![
+            serde_json::json!(1),
+            serde_json::json!(i64::from(i32::MAX) + 1),
+            serde_json::json!(1.5),
+        ];
+
+        let (field, data) = convert_column(values, &column).expect("conversion error");
+        assert_eq!(field.data_type(), &DataType::Float64);
+        let array = data
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .expect("expected Float64Array");
+        assert_eq!(array.value(0), 1.0);
+        assert_eq!(array.value(1), f64::from(i32::MAX) + 1.0);
+        assert_eq!(array.value(2), 1.5);
+    }
+
+    #[test]
+    fn convert_dynamic_column_empty_struct() {
+        let column = Column {
+            column_name: "dynamic_col".to_string(),
+            column_type: ColumnType::Dynamic,
+        };
+        let values = vec![serde_json::json!({})];
+
+        let (field, data) = convert_column(values, &column).expect("conversion error");
+        assert_eq!(field.data_type(), &DataType::Struct(Fields::empty()));
+        let array = data
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .expect("expected StructArray");
+        assert_eq!(array.num_columns(), 0);
+        assert!(array.is_valid(0));
+    }
+
+    #[test]
+    fn convert_dynamic_column_raw() {
+        let column = Column {
+            column_name: "dynamic_col".to_string(),
+            column_type: ColumnType::Dynamic,
+        };
+        let values = vec![serde_json::json!({"a": 1})];
+        let options = ConvertOptions {
+            expand_dynamic: false,
+        };
+
+        let (field, data) =
+            convert_column_with_options(values, &column, &options).expect("conversion error");
+        assert_eq!(field.data_type(), &DataType::Utf8);
+        let array = data
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .expect("expected StringArray");
+        assert_eq!(array.value(0), r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn json_integration_round_trip() {
+        let table = DataTable {
+            table_id: 1,
+            table_name: "Deft".to_string(),
+            table_kind: TableKind::PrimaryResult,
+            columns: vec![
+                Column {
+                    column_name: "int_col".to_string(),
+                    column_type: ColumnType::Int,
+                },
+                Column {
+                    column_name: "real_col".to_string(),
+                    column_type: ColumnType::Real,
+                },
+            ],
+            rows: vec![
+                Value::Array(vec![Value::from(1), Value::String("NaN".to_string())]),
+                Value::Array(vec![Value::Null, Value::from(1.5)]),
+            ],
+        };
+        let batch = convert_table(table).expect("conversion error");
+
+        let file = json_integration::to_json_integration_file(std::slice::from_ref(&batch))
+            .expect("failed to serialize to json integration format");
+        let json = serde_json::to_string(&file).expect("failed to serialize json");
+        let file: json_integration::JsonIntegrationFile =
+            serde_json::from_str(&json).expect("failed to deserialize json");
+        let batches = json_integration::from_json_integration_file(&file)
+            .expect("failed to rebuild record batches");
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), batch.num_rows());
+        let rebuilt_real = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        // `convert_array_float` maps Kusto's "NaN" to null.
+        assert!(rebuilt_real.is_null(0));
+        assert_eq!(rebuilt_real.value(1), 1.5);
+    }
+
+    #[test]
+    fn json_integration_round_trip_timestamp_and_duration() {
+        let table = DataTable {
+            table_id: 1,
+            table_name: "Deft".to_string(),
+            table_kind: TableKind::PrimaryResult,
+            columns: vec![
+                Column {
+                    column_name: "datetime_col".to_string(),
+                    column_type: ColumnType::Datetime,
+                },
+                Column {
+                    column_name: "timespan_col".to_string(),
+                    column_type: ColumnType::Timespan,
+                },
+            ],
+            rows: vec![
+                Value::Array(vec![
+                    Value::String("2021-06-01T12:00:00Z".to_string()),
+                    Value::String("00:00:01".to_string()),
+                ]),
+                Value::Array(vec![Value::Null, Value::Null]),
+            ],
+        };
+        let batch = convert_table(table).expect("conversion error");
+
+        let file = json_integration::to_json_integration_file(std::slice::from_ref(&batch))
+            .expect("failed to serialize to json integration format");
+        let json = serde_json::to_string(&file).expect("failed to serialize json");
+        let file: json_integration::JsonIntegrationFile =
+            serde_json::from_str(&json).expect("failed to deserialize json");
+        let batches = json_integration::from_json_integration_file(&file)
+            .expect("failed to rebuild record batches");
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0], batch);
+    }
+
+    #[test]
+    fn json_integration_round_trip_decimal_struct_list() {
+        let table = DataTable {
+            table_id: 1,
+            table_name: "Deft".to_string(),
+            table_kind: TableKind::PrimaryResult,
+            columns: vec![
+                Column {
+                    column_name: "decimal_col".to_string(),
+                    column_type: ColumnType::Decimal,
+                },
+                Column {
+                    column_name: "dynamic_col".to_string(),
+                    column_type: ColumnType::Dynamic,
+                },
+            ],
+            rows: vec![
+                Value::Array(vec![
+                    Value::String("1.50".to_string()),
+                    serde_json::json!({"a": 1, "b": [1, 2, 3]}),
+                ]),
+                Value::Array(vec![Value::Null, Value::Null]),
+            ],
+        };
+        let batch = convert_table(table).expect("conversion error");
+
+        let file = json_integration::to_json_integration_file(std::slice::from_ref(&batch))
+            .expect("failed to serialize to json integration format");
+        let json = serde_json::to_string(&file).expect("failed to serialize json");
+        let file: json_integration::JsonIntegrationFile =
+            serde_json::from_str(&json).expect("failed to deserialize json");
+        let batches = json_integration::from_json_integration_file(&file)
+            .expect("failed to rebuild record batches");
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0], batch);
+    }
+
+    #[test]
+    fn convert_table_streaming_chunks_rows() {
+        let table = int_column_table(0..5);
+
+        let batches = convert_table_streaming(table, 2, ConvertOptions::default())
+            .expect("failed to build streaming iterator")
+            .collect::<Result<Vec<_>>>()
+            .expect("conversion error");
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(batches[1].num_rows(), 2);
+        assert_eq!(batches[2].num_rows(), 1);
+    }
+
+    #[test]
+    fn convert_table_streaming_rejects_zero_batch_size() {
+        let table = int_column_table([1]);
+
+        let result = convert_table_streaming(table, 0, ConvertOptions::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn response_record_batches_streaming_chunks_rows() {
+        let response = response_with_int_rows(0..5);
+
+        let batches = response
+            .record_batches_streaming(2)
+            .expect("failed to build streaming iterator")
+            .collect::<Result<Vec<_>>>()
+            .expect("conversion error");
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(batches[1].num_rows(), 2);
+        assert_eq!(batches[2].num_rows(), 1);
+    }
+
+    #[test]
+    fn response_record_batches_streaming_rejects_zero_batch_size() {
+        let response = response_with_int_rows([1]);
+
+        let result = response.record_batches_streaming(0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn write_and_read_parquet_file() {
+        let table = int_column_table([42]);
+        let batch = convert_table(table).expect("conversion error");
+
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "azure-kusto-arrow-test-{:?}.parquet",
+            std::thread::current().id()
+        ));
+        let file = std::fs::File::create(&path).expect("failed to create temp file");
+        write_parquet(
+            batch.schema(),
+            std::iter::once(Ok(batch.clone())),
+            file,
+            None,
+        )
+        .expect("failed to write parquet file");
+
+        let file = std::fs::File::open(&path).expect("failed to reopen temp file");
+        let reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)
+            .expect("failed to open parquet file")
+            .build()
+            .expect("failed to build parquet reader");
+        let read_batches = reader
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .expect("failed to read record batches");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(read_batches.len(), 1);
+        assert_eq!(read_batches[0].num_rows(), batch.num_rows());
+    }
+
+    #[test]
+    fn write_and_read_ipc_file() {
+        let table = int_column_table([42]);
+        let batch = convert_table(table).expect("conversion error");
+
+        let bytes = to_ipc_bytes(batch.schema().as_ref(), std::iter::once(Ok(batch.clone())))
+            .expect("failed to write ipc bytes");
+
+        let reader = arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(bytes), None)
+            .expect("failed to read ipc bytes");
+        let read_batches = reader
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .expect("failed to read record batches");
+
+        assert_eq!(read_batches.len(), 1);
+        assert_eq!(read_batches[0], batch);
+    }
+
     #[test]
     fn read_data_types() {
         let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -220,4 +1212,93 @@ mod tests {
         assert!(record_batches[0].num_columns() > 0);
         assert!(record_batches[0].num_rows() > 0);
     }
+
+    /// A `V2QueryResult` frame wrapping a single `int_column_table`-shaped
+    /// primary result table, the same frame shape `read_data_types` above
+    /// deserializes from `tests/inputs/dataframe.json`.
+    fn response_with_int_rows(rows: impl IntoIterator<Item = i64>) -> KustoResponseDataSetV2 {
+        let rows: Vec<String> = rows.into_iter().map(|i| format!("[{i}]")).collect();
+        let data = format!(
+            r#"[{{
+                "FrameType": "DataTable",
+                "TableId": 1,
+                "TableName": "Deft",
+                "TableKind": "PrimaryResult",
+                "Columns": [{{"ColumnName": "int_col", "ColumnType": "int"}}],
+                "Rows": [{}]
+            }}]"#,
+            rows.join(",")
+        );
+        let results: Vec<V2QueryResult> =
+            serde_json::from_str(&data).expect("failed to deserialize V2QueryResult");
+        KustoResponseDataSetV2 { results }
+    }
+
+    #[test]
+    fn response_write_ipc_round_trips() {
+        let response = response_with_int_rows([42]);
+
+        let bytes = response
+            .to_ipc_bytes()
+            .expect("failed to serialize response to ipc bytes");
+        let reader = arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(bytes), None)
+            .expect("failed to read ipc bytes");
+        let read_batches = reader
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .expect("failed to read record batches");
+
+        assert_eq!(read_batches.len(), 1);
+        assert_eq!(read_batches[0].num_rows(), 1);
+    }
+
+    #[test]
+    fn response_with_no_results_writes_empty_ipc_schema() {
+        let response = KustoResponseDataSetV2 { results: vec![] };
+
+        let bytes = response
+            .to_ipc_bytes()
+            .expect("failed to serialize empty response to ipc bytes");
+        let reader = arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(bytes), None)
+            .expect("failed to read ipc bytes");
+        assert_eq!(reader.schema().fields().len(), 0);
+    }
+
+    #[test]
+    fn response_write_parquet_round_trips() {
+        let response = response_with_int_rows([42]);
+
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "azure-kusto-arrow-test-response-{:?}.parquet",
+            std::thread::current().id()
+        ));
+        let file = std::fs::File::create(&path).expect("failed to create temp file");
+        response
+            .write_parquet(file, None)
+            .expect("failed to write response to parquet");
+
+        let file = std::fs::File::open(&path).expect("failed to reopen temp file");
+        let reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)
+            .expect("failed to open parquet file")
+            .build()
+            .expect("failed to build parquet reader");
+        let read_batches = reader
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .expect("failed to read record batches");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(read_batches.len(), 1);
+        assert_eq!(read_batches[0].num_rows(), 1);
+    }
+
+    #[test]
+    fn response_with_no_results_writes_empty_parquet() {
+        let response = KustoResponseDataSetV2 { results: vec![] };
+
+        let mut buffer = Vec::new();
+        response
+            .write_parquet(&mut buffer, None)
+            .expect("failed to write empty response to parquet");
+        assert!(!buffer.is_empty());
+    }
 }
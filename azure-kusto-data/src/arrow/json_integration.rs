@@ -0,0 +1,552 @@
+//! A JSON format modelled on Apache Arrow's JSON integration-test format (a `schema` plus
+//! `batches` of `VALIDITY`/`DATA`/`OFFSET` columns), for golden-file testing of `convert_column`.
+//! Field types are written as plain strings such as `int32` or `decimal(3,2)` rather than
+//! Arrow's type objects, so these files are not interchangeable with Arrow's own tooling.
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Decimal128Array, DurationNanosecondArray, Float64Array,
+    Int32Array, Int64Array, ListArray, StringArray, StructArray, TimestampNanosecondArray,
+};
+use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::Result;
+
+use super::safe_map_f64;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonSchema {
+    pub fields: Vec<JsonField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub nullable: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<JsonField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonColumn {
+    pub name: String,
+    pub count: usize,
+    #[serde(rename = "VALIDITY")]
+    pub validity: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "DATA")]
+    pub data: Option<Vec<Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "OFFSET")]
+    pub offset: Option<Vec<i64>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<JsonColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRecordBatch {
+    pub count: usize,
+    pub columns: Vec<JsonColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonIntegrationFile {
+    pub schema: JsonSchema,
+    pub batches: Vec<JsonRecordBatch>,
+}
+
+fn type_name(data_type: &DataType) -> Result<String> {
+    Ok(match data_type {
+        DataType::Utf8 => "utf8".to_string(),
+        DataType::Boolean => "bool".to_string(),
+        DataType::Int32 => "int32".to_string(),
+        DataType::Int64 => "int64".to_string(),
+        DataType::Float64 => "floatingpoint[64]".to_string(),
+        DataType::Timestamp(TimeUnit::Nanosecond, None) => "timestamp[ns]".to_string(),
+        DataType::Duration(TimeUnit::Nanosecond) => "duration[ns]".to_string(),
+        DataType::Decimal128(precision, scale) => format!("decimal({precision},{scale})"),
+        DataType::List(_) => "list".to_string(),
+        DataType::Struct(_) => "struct".to_string(),
+        other => {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Unsupported Arrow type for JSON integration format: {other:?}"),
+            ))
+        }
+    })
+}
+
+fn parse_type(type_name: &str, children: &[JsonField]) -> Result<DataType> {
+    if let Some(dims) = type_name
+        .strip_prefix("decimal(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        let (precision, scale) = dims.split_once(',').ok_or_else(|| {
+            Error::message(
+                ErrorKind::DataConversion,
+                format!("Invalid decimal type spec: {type_name}"),
+            )
+        })?;
+        let precision: u8 = precision.trim().parse().map_err(|_| {
+            Error::message(
+                ErrorKind::DataConversion,
+                format!("Invalid decimal precision in: {type_name}"),
+            )
+        })?;
+        let scale: i8 = scale.trim().parse().map_err(|_| {
+            Error::message(
+                ErrorKind::DataConversion,
+                format!("Invalid decimal scale in: {type_name}"),
+            )
+        })?;
+        return Ok(DataType::Decimal128(precision, scale));
+    }
+
+    Ok(match type_name {
+        "utf8" => DataType::Utf8,
+        "bool" => DataType::Boolean,
+        "int32" => DataType::Int32,
+        "int64" => DataType::Int64,
+        "floatingpoint[64]" => DataType::Float64,
+        "timestamp[ns]" => DataType::Timestamp(TimeUnit::Nanosecond, None),
+        "duration[ns]" => DataType::Duration(TimeUnit::Nanosecond),
+        "list" => {
+            let item = children.first().ok_or_else(|| {
+                Error::message(ErrorKind::DataConversion, "List type missing item child")
+            })?;
+            DataType::List(Arc::new(json_field_to_field(item)?))
+        }
+        "struct" => DataType::Struct(Fields::from(
+            children
+                .iter()
+                .map(json_field_to_field)
+                .collect::<Result<Vec<_>>>()?,
+        )),
+        other => {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Unsupported JSON integration type: {other}"),
+            ))
+        }
+    })
+}
+
+fn field_to_json_field(field: &Field) -> Result<JsonField> {
+    let children = match field.data_type() {
+        DataType::List(item) => vec![field_to_json_field(item)?],
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(|f| field_to_json_field(f))
+            .collect::<Result<Vec<_>>>()?,
+        _ => Vec::new(),
+    };
+    Ok(JsonField {
+        name: field.name().clone(),
+        type_name: type_name(field.data_type())?,
+        nullable: field.is_nullable(),
+        children,
+    })
+}
+
+fn json_field_to_field(field: &JsonField) -> Result<Field> {
+    Ok(Field::new(
+        &field.name,
+        parse_type(&field.type_name, &field.children)?,
+        field.nullable,
+    ))
+}
+
+pub fn schema_to_json(schema: &Schema) -> Result<JsonSchema> {
+    Ok(JsonSchema {
+        fields: schema
+            .fields()
+            .iter()
+            .map(|f| field_to_json_field(f))
+            .collect::<Result<Vec<_>>>()?,
+    })
+}
+
+pub fn json_to_schema(schema: &JsonSchema) -> Result<Schema> {
+    Ok(Schema::new(
+        schema
+            .fields
+            .iter()
+            .map(json_field_to_field)
+            .collect::<Result<Vec<_>>>()?,
+    ))
+}
+
+fn validity(array: &dyn Array) -> Vec<u8> {
+    (0..array.len())
+        .map(|i| u8::from(array.is_valid(i)))
+        .collect()
+}
+
+fn float_to_json_value(value: f64) -> Value {
+    if value.is_nan() {
+        Value::String("NaN".to_string())
+    } else if value == f64::INFINITY {
+        Value::String("Infinity".to_string())
+    } else if value == f64::NEG_INFINITY {
+        Value::String("-Infinity".to_string())
+    } else {
+        serde_json::Number::from_f64(value)
+            .map(Value::Number)
+            .unwrap_or(Value::Null)
+    }
+}
+
+fn array_to_json_column(name: &str, data_type: &DataType, array: &ArrayRef) -> Result<JsonColumn> {
+    let count = array.len();
+    let validity = validity(array.as_ref());
+
+    let (data, offset, children) = match data_type {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::Bool(array.value(i))
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Int32 => {
+            let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::from(array.value(i))
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Int64 => {
+            let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        // 64-bit integers are stringified, matching the Arrow
+                        // integration format (JSON numbers aren't guaranteed
+                        // to round-trip full 64-bit precision).
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Float64 => {
+            let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        float_to_json_value(array.value(i))
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, None) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<TimestampNanosecondArray>()
+                .unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<DurationNanosecondArray>()
+                .unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::Decimal128(_, _) => {
+            let array = array.as_any().downcast_ref::<Decimal128Array>().unwrap();
+            let data = (0..count)
+                .map(|i| {
+                    if array.is_null(i) {
+                        Value::Null
+                    } else {
+                        Value::String(array.value(i).to_string())
+                    }
+                })
+                .collect();
+            (Some(data), None, Vec::new())
+        }
+        DataType::List(item_field) => {
+            let array = array.as_any().downcast_ref::<ListArray>().unwrap();
+            let offsets = array.value_offsets().iter().map(|o| *o as i64).collect();
+            let child = array_to_json_column("item", item_field.data_type(), array.values())?;
+            (None, Some(offsets), vec![child])
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let children = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    array_to_json_column(field.name(), field.data_type(), array.column(i))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            (None, None, children)
+        }
+        other => {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Unsupported Arrow type for JSON integration format: {other:?}"),
+            ))
+        }
+    };
+
+    Ok(JsonColumn {
+        name: name.to_string(),
+        count,
+        validity,
+        data,
+        offset,
+        children,
+    })
+}
+
+fn json_column_to_array(column: &JsonColumn, data_type: &DataType) -> Result<ArrayRef> {
+    let is_valid = |i: usize| column.validity.get(i).copied().unwrap_or(0) != 0;
+
+    Ok(match data_type {
+        DataType::Utf8 => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<String>> = (0..column.count)
+                .map(|i| is_valid(i).then(|| data[i].as_str().unwrap_or_default().to_string()))
+                .collect();
+            Arc::new(StringArray::from(values))
+        }
+        DataType::Boolean => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<bool>> = (0..column.count)
+                .map(|i| is_valid(i).then(|| data[i].as_bool().unwrap_or_default()))
+                .collect();
+            Arc::new(BooleanArray::from(values))
+        }
+        DataType::Int32 => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<i32>> = (0..column.count)
+                .map(|i| is_valid(i).then(|| data[i].as_i64().unwrap_or_default() as i32))
+                .collect();
+            Arc::new(Int32Array::from(values))
+        }
+        DataType::Int64 => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<i64>> = (0..column.count)
+                .map(|i| {
+                    is_valid(i).then(|| {
+                        data[i]
+                            .as_str()
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect();
+            Arc::new(Int64Array::from(values))
+        }
+        DataType::Float64 => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<f64>> = (0..column.count)
+                .map(|i| {
+                    is_valid(i).then(|| {
+                        safe_map_f64(data[i].clone())
+                            .ok()
+                            .flatten()
+                            .unwrap_or(f64::NAN)
+                    })
+                })
+                .collect();
+            Arc::new(Float64Array::from(values))
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, None) => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<i64>> = (0..column.count)
+                .map(|i| {
+                    is_valid(i).then(|| {
+                        data[i]
+                            .as_str()
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect();
+            Arc::new(TimestampNanosecondArray::from(values))
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<i64>> = (0..column.count)
+                .map(|i| {
+                    is_valid(i).then(|| {
+                        data[i]
+                            .as_str()
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect();
+            Arc::new(DurationNanosecondArray::from(values))
+        }
+        DataType::Decimal128(precision, scale) => {
+            let data = column.data.as_ref().ok_or_else(missing_data)?;
+            let values: Vec<Option<i128>> = (0..column.count)
+                .map(|i| {
+                    is_valid(i).then(|| {
+                        data[i]
+                            .as_str()
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect();
+            Arc::new(
+                Decimal128Array::from(values)
+                    .with_precision_and_scale(*precision, *scale)
+                    .context(ErrorKind::DataConversion, "Invalid decimal precision/scale")?,
+            )
+        }
+        DataType::List(item_field) => {
+            let offsets = column.offset.as_ref().ok_or_else(missing_offset)?;
+            let child_column = column.children.first().ok_or_else(|| {
+                Error::message(ErrorKind::DataConversion, "List column missing item child")
+            })?;
+            let child = json_column_to_array(child_column, item_field.data_type())?;
+            let offsets: Vec<i32> = offsets.iter().map(|o| *o as i32).collect();
+            let nulls = arrow::buffer::NullBuffer::from(
+                (0..column.count).map(is_valid).collect::<Vec<_>>(),
+            );
+            Arc::new(
+                ListArray::try_new(
+                    Arc::new(item_field.as_ref().clone()),
+                    arrow::buffer::OffsetBuffer::new(offsets.into()),
+                    child,
+                    Some(nulls),
+                )
+                .context(ErrorKind::DataConversion, "Failed to rebuild list array")?,
+            )
+        }
+        DataType::Struct(fields) => {
+            let children = fields
+                .iter()
+                .zip(column.children.iter())
+                .map(|(field, child)| json_column_to_array(child, field.data_type()))
+                .collect::<Result<Vec<_>>>()?;
+            let nulls = arrow::buffer::NullBuffer::from(
+                (0..column.count).map(is_valid).collect::<Vec<_>>(),
+            );
+            Arc::new(
+                StructArray::try_new(fields.clone(), children, Some(nulls))
+                    .context(ErrorKind::DataConversion, "Failed to rebuild struct array")?,
+            )
+        }
+        other => {
+            return Err(Error::message(
+                ErrorKind::DataConversion,
+                format!("Unsupported JSON integration type: {other:?}"),
+            ))
+        }
+    })
+}
+
+fn missing_data() -> Error {
+    Error::message(ErrorKind::DataConversion, "Column is missing DATA")
+}
+
+fn missing_offset() -> Error {
+    Error::message(ErrorKind::DataConversion, "Column is missing OFFSET")
+}
+
+pub fn record_batch_to_json(batch: &RecordBatch) -> Result<JsonRecordBatch> {
+    let columns = batch
+        .schema()
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| array_to_json_column(field.name(), field.data_type(), array))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(JsonRecordBatch {
+        count: batch.num_rows(),
+        columns,
+    })
+}
+
+pub fn record_batch_from_json(schema: &Schema, batch: &JsonRecordBatch) -> Result<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns.iter())
+        .map(|(field, column)| json_column_to_array(column, field.data_type()))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(Arc::new(schema.clone()), columns)
+        .context(ErrorKind::DataConversion, "Failed to rebuild record batch")
+}
+
+pub fn to_json_integration_file(batches: &[RecordBatch]) -> Result<JsonIntegrationFile> {
+    let schema = batches
+        .first()
+        .map(|b| b.schema())
+        .ok_or_else(|| Error::message(ErrorKind::DataConversion, "No batches to serialize"))?;
+    Ok(JsonIntegrationFile {
+        schema: schema_to_json(&schema)?,
+        batches: batches
+            .iter()
+            .map(record_batch_to_json)
+            .collect::<Result<Vec<_>>>()?,
+    })
+}
+
+pub fn from_json_integration_file(file: &JsonIntegrationFile) -> Result<Vec<RecordBatch>> {
+    let schema = json_to_schema(&file.schema)?;
+    file.batches
+        .iter()
+        .map(|batch| record_batch_from_json(&schema, batch))
+        .collect()
+}